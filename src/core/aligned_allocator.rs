@@ -0,0 +1,264 @@
+//! An allocator that backs its `Dynamic`-sized buffers with an over-aligned heap allocation, so
+//! that the contiguous storage can be read/written with aligned SIMD loads and stores.
+
+use std::alloc::{alloc, dealloc, handle_alloc_error, Layout};
+use std::mem;
+use std::mem::MaybeUninit;
+use std::ops::{Deref, DerefMut};
+use std::ptr;
+
+use num::Zero;
+
+use core::Scalar;
+use core::dimension::{Dim, DimName, Dynamic};
+use core::allocator::Allocator;
+
+/*
+ *
+ * AlignedAllocator.
+ *
+ */
+/// An allocator whose `Dynamic`-dimensioned buffers are aligned on an `ALIGN`-byte boundary
+/// (16 or 32 are the common choices for SSE/AVX loads) instead of nalgebra's usual
+/// `align_of::<N>()` guarantee.
+///
+/// This only changes the alignment of the backing allocation; the numerical behavior of
+/// matrices built on top of it is identical to `DefaultAllocator`.
+///
+/// Unlike `DefaultAllocator`, this allocator is dynamic only: `Allocator` is implemented for
+/// `(Dynamic, C)` and `(R, Dynamic)` shapes, not for shapes whose dimensions are both known at
+/// compile-time.
+pub struct AlignedAllocator<const ALIGN: usize>;
+
+/// A heap buffer manually allocated with an `ALIGN`-byte alignment.
+///
+/// Unlike `MatrixVec`, this does not wrap a `Vec` because `Vec` cannot be asked to over-align
+/// its allocation; the buffer instead owns a raw pointer obtained from `std::alloc::alloc` and
+/// is responsible for deallocating it with a matching `Layout` on `Drop`.
+pub struct AlignedMatrixVec<N, R: Dim, C: Dim, const ALIGN: usize> {
+    ptr:   *mut N,
+    len:   usize,
+    nrows: R,
+    ncols: C,
+}
+
+impl<N, R: Dim, C: Dim, const ALIGN: usize> AlignedMatrixVec<N, R, C, ALIGN> {
+    fn layout(len: usize) -> Layout {
+        assert!(ALIGN.is_power_of_two() && ALIGN >= mem::align_of::<N>(),
+                "AlignedAllocator: ALIGN ({}) must be a power of two at least as large as align_of::<N>() ({})",
+                ALIGN, mem::align_of::<N>());
+
+        let size = len.checked_mul(mem::size_of::<N>())
+                      .expect("AlignedAllocator: buffer size overflows usize");
+
+        Layout::from_size_align(size, ALIGN).expect("invalid alignment for AlignedAllocator")
+    }
+
+    unsafe fn allocate(nrows: R, ncols: C) -> Self {
+        let len = nrows.value() * ncols.value();
+        let ptr = if len == 0 {
+            ptr::NonNull::dangling().as_ptr()
+        }
+        else {
+            let layout = Self::layout(len);
+            let ptr = alloc(layout);
+
+            if ptr.is_null() {
+                handle_alloc_error(layout);
+            }
+
+            ptr as *mut N
+        };
+
+        AlignedMatrixVec { ptr, len, nrows, ncols }
+    }
+
+    /// The elements of this buffer as a contiguous slice.
+    #[inline]
+    pub fn as_slice(&self) -> &[N] {
+        unsafe { ::std::slice::from_raw_parts(self.ptr, self.len) }
+    }
+
+    /// The elements of this buffer as a mutable contiguous slice.
+    #[inline]
+    pub fn as_mut_slice(&mut self) -> &mut [N] {
+        unsafe { ::std::slice::from_raw_parts_mut(self.ptr, self.len) }
+    }
+}
+
+impl<N, R: Dim, C: Dim, const ALIGN: usize> AlignedMatrixVec<MaybeUninit<N>, R, C, ALIGN> {
+    /// Asserts that every element of this buffer has been initialized, and converts it into a
+    /// fully initialized `AlignedMatrixVec<N, R, C, ALIGN>`.
+    ///
+    /// # Safety
+    /// The caller must ensure that every element has actually been written to.
+    #[inline]
+    pub unsafe fn assume_init(self) -> AlignedMatrixVec<N, R, C, ALIGN> {
+        let me = mem::ManuallyDrop::new(self);
+        AlignedMatrixVec { ptr: me.ptr as *mut N, len: me.len, nrows: me.nrows, ncols: me.ncols }
+    }
+}
+
+impl<N: Clone, R: Dim, C: Dim, const ALIGN: usize> Clone for AlignedMatrixVec<N, R, C, ALIGN> {
+    fn clone(&self) -> Self {
+        // Allocate the clone as `MaybeUninit<N>` (rather than typing the fresh allocation as
+        // `N` directly) so the not-yet-written slots are never read or dropped as a live `N`,
+        // matching the `allocate_uninitialized`/`assume_init` pattern used elsewhere.
+        let mut res: AlignedMatrixVec<MaybeUninit<N>, R, C, ALIGN> = unsafe { AlignedMatrixVec::allocate(self.nrows, self.ncols) };
+
+        for (dst, src) in res.iter_mut().zip(self.iter()) {
+            *dst = MaybeUninit::new(src.clone());
+        }
+
+        unsafe { res.assume_init() }
+    }
+}
+
+impl<N, R: Dim, C: Dim, const ALIGN: usize> Drop for AlignedMatrixVec<N, R, C, ALIGN> {
+    fn drop(&mut self) {
+        if self.len != 0 {
+            unsafe {
+                ptr::drop_in_place(ptr::slice_from_raw_parts_mut(self.ptr, self.len));
+                dealloc(self.ptr as *mut u8, Self::layout(self.len));
+            }
+        }
+    }
+}
+
+impl<N, R: Dim, C: Dim, const ALIGN: usize> Deref for AlignedMatrixVec<N, R, C, ALIGN> {
+    type Target = [N];
+
+    #[inline]
+    fn deref(&self) -> &[N] {
+        unsafe { ::std::slice::from_raw_parts(self.ptr, self.len) }
+    }
+}
+
+impl<N, R: Dim, C: Dim, const ALIGN: usize> DerefMut for AlignedMatrixVec<N, R, C, ALIGN> {
+    #[inline]
+    fn deref_mut(&mut self) -> &mut [N] {
+        unsafe { ::std::slice::from_raw_parts_mut(self.ptr, self.len) }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::mem::MaybeUninit;
+
+    use core::dimension::Dynamic;
+
+    use super::AlignedMatrixVec;
+
+    #[test]
+    fn as_slice_is_aligned() {
+        let nrows = Dynamic::new(5);
+        let ncols = Dynamic::new(3);
+        let mut buf: AlignedMatrixVec<MaybeUninit<f64>, Dynamic, Dynamic, 32> = unsafe { AlignedMatrixVec::allocate(nrows, ncols) };
+
+        for e in buf.iter_mut() {
+            *e = MaybeUninit::new(0.0);
+        }
+
+        let buf = unsafe { buf.assume_init() };
+        assert_eq!(buf.as_slice().as_ptr() as usize % 32, 0);
+    }
+
+    #[test]
+    #[should_panic]
+    fn layout_rejects_under_alignment() {
+        let _ = AlignedMatrixVec::<f64, Dynamic, Dynamic, 4>::layout(8);
+    }
+
+    #[test]
+    #[should_panic]
+    fn layout_rejects_size_overflow() {
+        let _ = AlignedMatrixVec::<f64, Dynamic, Dynamic, 32>::layout(usize::max_value());
+    }
+}
+
+// Dynamic - Static
+// Dynamic - Dynamic
+impl<N: Scalar, C: Dim, const ALIGN: usize> Allocator<N, Dynamic, C> for AlignedAllocator<ALIGN> {
+    type Buffer       = AlignedMatrixVec<N, Dynamic, C, ALIGN>;
+    type BufferUninit = AlignedMatrixVec<MaybeUninit<N>, Dynamic, C, ALIGN>;
+
+    #[inline]
+    unsafe fn allocate_uninitialized(nrows: Dynamic, ncols: C) -> Self::BufferUninit {
+        AlignedMatrixVec::allocate(nrows, ncols)
+    }
+
+    #[inline]
+    unsafe fn assume_init(uninit: Self::BufferUninit) -> Self::Buffer {
+        uninit.assume_init()
+    }
+
+    #[inline]
+    fn allocate_from_iterator<I: IntoIterator<Item = N>>(nrows: Dynamic, ncols: C, iter: I) -> Self::Buffer {
+        let mut res = unsafe { Self::allocate_uninitialized(nrows, ncols) };
+        let mut count = 0;
+
+        for (res, e) in res.iter_mut().zip(iter.into_iter()) {
+            *res = MaybeUninit::new(e);
+            count += 1;
+        }
+
+        assert!(count == nrows.value() * ncols.value(),
+                "Matrix init. from iterator: iterator not long enough.");
+
+        unsafe { Self::assume_init(res) }
+    }
+
+    #[inline]
+    fn allocate_zeroed(nrows: Dynamic, ncols: C) -> Self::Buffer where N: Zero {
+        let mut res = unsafe { Self::allocate_uninitialized(nrows, ncols) };
+
+        for e in res.iter_mut() {
+            *e = MaybeUninit::new(N::zero());
+        }
+
+        unsafe { Self::assume_init(res) }
+    }
+}
+
+// Static - Dynamic
+impl<N: Scalar, R: DimName, const ALIGN: usize> Allocator<N, R, Dynamic> for AlignedAllocator<ALIGN> {
+    type Buffer       = AlignedMatrixVec<N, R, Dynamic, ALIGN>;
+    type BufferUninit = AlignedMatrixVec<MaybeUninit<N>, R, Dynamic, ALIGN>;
+
+    #[inline]
+    unsafe fn allocate_uninitialized(nrows: R, ncols: Dynamic) -> Self::BufferUninit {
+        AlignedMatrixVec::allocate(nrows, ncols)
+    }
+
+    #[inline]
+    unsafe fn assume_init(uninit: Self::BufferUninit) -> Self::Buffer {
+        uninit.assume_init()
+    }
+
+    #[inline]
+    fn allocate_from_iterator<I: IntoIterator<Item = N>>(nrows: R, ncols: Dynamic, iter: I) -> Self::Buffer {
+        let mut res = unsafe { Self::allocate_uninitialized(nrows, ncols) };
+        let mut count = 0;
+
+        for (res, e) in res.iter_mut().zip(iter.into_iter()) {
+            *res = MaybeUninit::new(e);
+            count += 1;
+        }
+
+        assert!(count == nrows.value() * ncols.value(),
+                "Matrix init. from iterator: iterator not long enough.");
+
+        unsafe { Self::assume_init(res) }
+    }
+
+    #[inline]
+    fn allocate_zeroed(nrows: R, ncols: Dynamic) -> Self::Buffer where N: Zero {
+        let mut res = unsafe { Self::allocate_uninitialized(nrows, ncols) };
+
+        for e in res.iter_mut() {
+            *e = MaybeUninit::new(N::zero());
+        }
+
+        unsafe { Self::assume_init(res) }
+    }
+}
@@ -0,0 +1,79 @@
+//! Abstract definition of a matrix data storage allocator.
+
+use std::any::Any;
+
+use num::Zero;
+
+use core::Scalar;
+use core::dimension::Dim;
+use core::storage::ContiguousStorageMut;
+
+/// A matrix allocator of a memory buffer that may contain `R::to_usize() * C::to_usize()`
+/// elements of type `N`.
+///
+/// An allocator is said to be:
+///   − static:  if `R` and `C` both implement `DimName`.
+///   − dynamic: if either one (or both) of `R` or `C` does not implement `DimName`.
+///
+/// `DefaultAllocator` is both static and dynamic, though not all of its implementations share the
+/// same `Buffer` type; `AlignedAllocator` is dynamic only.
+pub trait Allocator<N: Scalar, R: Dim, C: Dim>: Any + Sized {
+    /// The type of buffer this allocator can instanciate.
+    ///
+    /// Bounding this on `Clone` lets generic code duplicate a matrix's storage without having
+    /// to go through `DefaultAllocator` again, and the contiguous-storage bound guarantees every
+    /// `Buffer` exposes its elements as a single contiguous slice (see `as_slice`/`as_mut_slice`
+    /// on `MatrixArray` and `MatrixVec`), which algorithms that need raw contiguous access (FFI,
+    /// BLAS-style kernels) can rely on regardless of the concrete allocator in use.
+    type Buffer: ContiguousStorageMut<N, R, C> + Clone;
+
+    /// The type of buffer this allocator instanciates before all of its elements have been
+    /// written to.
+    ///
+    /// Reading from a `BufferUninit` (or dropping it as if it were a `Buffer`) before every
+    /// element has been initialized is undefined behavior; call `assume_init` once the buffer
+    /// is fully populated.
+    type BufferUninit;
+
+    /// Allocates a buffer with the given number of rows and columns, leaving its content
+    /// uninitialized.
+    unsafe fn allocate_uninitialized(nrows: R, ncols: C) -> Self::BufferUninit;
+
+    /// Asserts that every element of `buffer` has been initialized, and converts it into the
+    /// corresponding fully initialized `Buffer`.
+    ///
+    /// # Safety
+    /// The caller must ensure that every element of `buffer` has actually been written to.
+    unsafe fn assume_init(buffer: Self::BufferUninit) -> Self::Buffer;
+
+    /// Allocates a buffer initialized with the content of the given iterator.
+    fn allocate_from_iterator<I: IntoIterator<Item = N>>(nrows: R, ncols: C, iter: I) -> Self::Buffer;
+
+    /// Allocates a buffer filled with zeroes.
+    ///
+    /// This is a safe function: callers do not need the `unsafe`
+    /// `allocate_uninitialized`/`assume_init` pair themselves, though an implementation may use
+    /// it internally.
+    fn allocate_zeroed(nrows: R, ncols: C) -> Self::Buffer where N: Zero;
+}
+
+/// An allocator that can reallocate an existing buffer to a different shape, reusing the
+/// underlying memory whenever possible instead of allocating a brand new one.
+///
+/// This is mostly useful for `Dynamic`-dimensioned matrices, whose backing `Vec` can be resized
+/// in place on a grow/shrink instead of being copied into a freshly allocated buffer.
+pub trait Reallocator<N: Scalar, RFrom: Dim, CFrom: Dim, RTo: Dim, CTo: Dim>: Allocator<N, RFrom, CFrom> + Allocator<N, RTo, CTo> {
+    /// Reallocates a buffer of shape `(nrows, ncols)`, possibly reusing the old buffer `buf` if
+    /// its storage supports it.
+    ///
+    /// The elements of `buf` that fit in the new shape are moved into the result; when the new
+    /// shape holds fewer elements, the extra tail elements of `buf` are dropped. The returned
+    /// `BufferUninit` is not completely
+    /// initialized either: any newly grown slots are left uninitialized, and it is the
+    /// responsibility of the caller to overwrite them before calling
+    /// `Allocator::assume_init` on the result.
+    ///
+    /// # Safety
+    /// The caller must not read `buf`'s old elements beyond what the new shape keeps alive.
+    unsafe fn reallocate_copy(nrows: RTo, ncols: CTo, buf: <Self as Allocator<N, RFrom, CFrom>>::Buffer) -> <Self as Allocator<N, RTo, CTo>>::BufferUninit;
+}
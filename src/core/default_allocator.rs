@@ -3,15 +3,16 @@
 //! This will use stack-allocated buffers for matrices with dimensions known at compile-time, and
 //! heap-allocated buffers for matrices with at least one dimension unknown at compile-time.
 
-use std::mem;
+use std::mem::MaybeUninit;
 use std::ops::Mul;
 
+use num::Zero;
 use typenum::Prod;
 use generic_array::ArrayLength;
 
 use core::Scalar;
 use core::dimension::{Dim, DimName, Dynamic};
-use core::allocator::Allocator;
+use core::allocator::{Allocator, Reallocator};
 use core::matrix_array::MatrixArray;
 use core::matrix_vec::MatrixVec;
 
@@ -30,12 +31,18 @@ impl<N, R, C> Allocator<N, R, C> for DefaultAllocator
           R: DimName,
           C: DimName,
           R::Value: Mul<C::Value>,
-          Prod<R::Value, C::Value>: ArrayLength<N> {
-    type Buffer = MatrixArray<N, R, C>;
+          Prod<R::Value, C::Value>: ArrayLength<N> + ArrayLength<MaybeUninit<N>> {
+    type Buffer        = MatrixArray<N, R, C>;
+    type BufferUninit  = MatrixArray<MaybeUninit<N>, R, C>;
 
     #[inline]
-    unsafe fn allocate_uninitialized(_: R, _: C) -> Self::Buffer {
-        mem::uninitialized()
+    unsafe fn allocate_uninitialized(_: R, _: C) -> Self::BufferUninit {
+        MatrixArray::new_uninitialized()
+    }
+
+    #[inline]
+    unsafe fn assume_init(uninit: Self::BufferUninit) -> Self::Buffer {
+        uninit.assume_init()
     }
 
     #[inline]
@@ -44,14 +51,25 @@ impl<N, R, C> Allocator<N, R, C> for DefaultAllocator
         let mut count = 0;
 
         for (res, e) in res.iter_mut().zip(iter.into_iter()) {
-            *res = e;
+            *res = MaybeUninit::new(e);
             count += 1;
         }
 
         assert!(count == nrows.value() * ncols.value(),
                 "Matrix init. from iterator: iterator not long enough.");
 
-        res
+        unsafe { Self::assume_init(res) }
+    }
+
+    #[inline]
+    fn allocate_zeroed(nrows: R, ncols: C) -> Self::Buffer where N: Zero {
+        let mut res = unsafe { Self::allocate_uninitialized(nrows, ncols) };
+
+        for e in res.iter_mut() {
+            *e = MaybeUninit::new(N::zero());
+        }
+
+        unsafe { Self::assume_init(res) }
     }
 }
 
@@ -59,18 +77,23 @@ impl<N, R, C> Allocator<N, R, C> for DefaultAllocator
 // Dynamic - Static
 // Dynamic - Dynamic
 impl<N: Scalar, C: Dim> Allocator<N, Dynamic, C> for DefaultAllocator {
-    type Buffer = MatrixVec<N, Dynamic, C>;
+    type Buffer       = MatrixVec<N, Dynamic, C>;
+    type BufferUninit = MatrixVec<MaybeUninit<N>, Dynamic, C>;
 
     #[inline]
-    unsafe fn allocate_uninitialized(nrows: Dynamic, ncols: C) -> Self::Buffer {
-        let mut res = Vec::new();
+    unsafe fn allocate_uninitialized(nrows: Dynamic, ncols: C) -> Self::BufferUninit {
         let length = nrows.value() * ncols.value();
-        res.reserve_exact(length);
+        let mut res = Vec::with_capacity(length);
         res.set_len(length);
 
         MatrixVec::new(nrows, ncols, res)
     }
 
+    #[inline]
+    unsafe fn assume_init(uninit: Self::BufferUninit) -> Self::Buffer {
+        uninit.assume_init()
+    }
+
     #[inline]
     fn allocate_from_iterator<I: IntoIterator<Item = N>>(nrows: Dynamic, ncols: C, iter: I) -> Self::Buffer {
         let it = iter.into_iter();
@@ -80,23 +103,40 @@ impl<N: Scalar, C: Dim> Allocator<N, Dynamic, C> for DefaultAllocator {
 
         MatrixVec::new(nrows, ncols, res)
     }
+
+    #[inline]
+    fn allocate_zeroed(nrows: Dynamic, ncols: C) -> Self::Buffer where N: Zero {
+        let length = nrows.value() * ncols.value();
+        let mut res = Vec::with_capacity(length);
+
+        for _ in 0..length {
+            res.push(N::zero());
+        }
+
+        MatrixVec::new(nrows, ncols, res)
+    }
 }
 
 
 // Static - Dynamic
 impl<N: Scalar, R: DimName> Allocator<N, R, Dynamic> for DefaultAllocator {
-    type Buffer = MatrixVec<N, R, Dynamic>;
+    type Buffer       = MatrixVec<N, R, Dynamic>;
+    type BufferUninit = MatrixVec<MaybeUninit<N>, R, Dynamic>;
 
     #[inline]
-    unsafe fn allocate_uninitialized(nrows: R, ncols: Dynamic) -> Self::Buffer {
-        let mut res = Vec::new();
+    unsafe fn allocate_uninitialized(nrows: R, ncols: Dynamic) -> Self::BufferUninit {
         let length = nrows.value() * ncols.value();
-        res.reserve_exact(length);
+        let mut res = Vec::with_capacity(length);
         res.set_len(length);
 
         MatrixVec::new(nrows, ncols, res)
     }
 
+    #[inline]
+    unsafe fn assume_init(uninit: Self::BufferUninit) -> Self::Buffer {
+        uninit.assume_init()
+    }
+
     #[inline]
     fn allocate_from_iterator<I: IntoIterator<Item = N>>(nrows: R, ncols: Dynamic, iter: I) -> Self::Buffer {
         let it = iter.into_iter();
@@ -106,4 +146,74 @@ impl<N: Scalar, R: DimName> Allocator<N, R, Dynamic> for DefaultAllocator {
 
         MatrixVec::new(nrows, ncols, res)
     }
+
+    #[inline]
+    fn allocate_zeroed(nrows: R, ncols: Dynamic) -> Self::Buffer where N: Zero {
+        let length = nrows.value() * ncols.value();
+        let mut res = Vec::with_capacity(length);
+
+        for _ in 0..length {
+            res.push(N::zero());
+        }
+
+        MatrixVec::new(nrows, ncols, res)
+    }
+}
+
+/*
+ *
+ * Reallocator.
+ *
+ */
+// Dynamic - Dynamic
+impl<N: Scalar, CFrom: Dim, CTo: Dim> Reallocator<N, Dynamic, CFrom, Dynamic, CTo> for DefaultAllocator {
+    #[inline]
+    unsafe fn reallocate_copy(nrows: Dynamic, ncols: CTo, buf: MatrixVec<N, Dynamic, CFrom>) -> MatrixVec<MaybeUninit<N>, Dynamic, CTo> {
+        let new_buf = buf.resize(nrows.value() * ncols.value());
+        MatrixVec::new(nrows, ncols, new_buf)
+    }
+}
+
+// Static - Dynamic
+impl<N: Scalar, RFrom: DimName, CTo: Dim> Reallocator<N, RFrom, Dynamic, Dynamic, CTo> for DefaultAllocator {
+    #[inline]
+    unsafe fn reallocate_copy(nrows: Dynamic, ncols: CTo, buf: MatrixVec<N, RFrom, Dynamic>) -> MatrixVec<MaybeUninit<N>, Dynamic, CTo> {
+        let new_buf = buf.resize(nrows.value() * ncols.value());
+        MatrixVec::new(nrows, ncols, new_buf)
+    }
+}
+
+// Dynamic - Static
+impl<N: Scalar, CFrom: Dim, RTo: DimName> Reallocator<N, Dynamic, CFrom, RTo, Dynamic> for DefaultAllocator {
+    #[inline]
+    unsafe fn reallocate_copy(nrows: RTo, ncols: Dynamic, buf: MatrixVec<N, Dynamic, CFrom>) -> MatrixVec<MaybeUninit<N>, RTo, Dynamic> {
+        let new_buf = buf.resize(nrows.value() * ncols.value());
+        MatrixVec::new(nrows, ncols, new_buf)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::mem::MaybeUninit;
+
+    use core::dimension::Dynamic;
+    use core::allocator::{Allocator, Reallocator};
+    use core::matrix_vec::MatrixVec;
+
+    use super::DefaultAllocator;
+
+    #[test]
+    fn reallocate_copy_grow_preserves_prefix_then_assume_init() {
+        let old: MatrixVec<i32, Dynamic, Dynamic> = MatrixVec::new(Dynamic::new(3), Dynamic::new(1), vec![1, 2, 3]);
+
+        let mut uninit = unsafe {
+            <DefaultAllocator as Reallocator<i32, Dynamic, Dynamic, Dynamic, Dynamic>>::reallocate_copy(Dynamic::new(5), Dynamic::new(1), old)
+        };
+
+        uninit.as_mut_slice()[3] = MaybeUninit::new(4);
+        uninit.as_mut_slice()[4] = MaybeUninit::new(5);
+
+        let grown = unsafe { <DefaultAllocator as Allocator<i32, Dynamic, Dynamic>>::assume_init(uninit) };
+        assert_eq!(grown.as_slice(), &[1, 2, 3, 4, 5]);
+    }
 }
@@ -0,0 +1,104 @@
+use std::mem;
+use std::mem::MaybeUninit;
+use std::ops::{Deref, DerefMut, Mul};
+use std::ptr;
+
+use typenum::Prod;
+use generic_array::{ArrayLength, GenericArray};
+
+use core::dimension::DimName;
+
+/// A array-based statically sized matrix data storage.
+#[repr(C)]
+pub struct MatrixArray<N, R, C>
+    where R: DimName, C: DimName, R::Value: Mul<C::Value>, Prod<R::Value, C::Value>: ArrayLength<N> {
+    data: GenericArray<N, Prod<R::Value, C::Value>>,
+}
+
+impl<N, R, C> MatrixArray<MaybeUninit<N>, R, C>
+    where R: DimName, C: DimName, R::Value: Mul<C::Value>, Prod<R::Value, C::Value>: ArrayLength<MaybeUninit<N>> {
+    /// Allocates a `MatrixArray` whose elements are left uninitialized.
+    ///
+    /// This is safe to construct (an uninitialized `MaybeUninit<N>` is itself a valid value),
+    /// but every element must be written to before the result is converted with `assume_init`.
+    #[inline]
+    pub fn new_uninitialized() -> Self {
+        MatrixArray { data: unsafe { MaybeUninit::uninit().assume_init() } }
+    }
+
+    /// Asserts that every element of this array has been initialized, and converts it into a
+    /// fully initialized `MatrixArray<N, R, C>`.
+    ///
+    /// # Safety
+    /// The caller must ensure that every element has actually been written to.
+    #[inline]
+    pub unsafe fn assume_init(self) -> MatrixArray<N, R, C>
+        where Prod<R::Value, C::Value>: ArrayLength<N> {
+        let data = ptr::read(&self.data as *const GenericArray<MaybeUninit<N>, Prod<R::Value, C::Value>>
+                                         as *const GenericArray<N, Prod<R::Value, C::Value>>);
+        mem::forget(self);
+        MatrixArray { data: data }
+    }
+}
+
+impl<N: Clone, R, C> Clone for MatrixArray<N, R, C>
+    where R: DimName, C: DimName, R::Value: Mul<C::Value>, Prod<R::Value, C::Value>: ArrayLength<N> {
+    #[inline]
+    fn clone(&self) -> Self {
+        MatrixArray { data: self.data.clone() }
+    }
+}
+
+impl<N, R, C> MatrixArray<N, R, C>
+    where R: DimName, C: DimName, R::Value: Mul<C::Value>, Prod<R::Value, C::Value>: ArrayLength<N> {
+    /// The elements of this buffer as a contiguous slice.
+    #[inline]
+    pub fn as_slice(&self) -> &[N] {
+        &self.data[..]
+    }
+
+    /// The elements of this buffer as a mutable contiguous slice.
+    #[inline]
+    pub fn as_mut_slice(&mut self) -> &mut [N] {
+        &mut self.data[..]
+    }
+}
+
+impl<N, R, C> Deref for MatrixArray<N, R, C>
+    where R: DimName, C: DimName, R::Value: Mul<C::Value>, Prod<R::Value, C::Value>: ArrayLength<N> {
+    type Target = [N];
+
+    #[inline]
+    fn deref(&self) -> &[N] {
+        &self.data[..]
+    }
+}
+
+impl<N, R, C> DerefMut for MatrixArray<N, R, C>
+    where R: DimName, C: DimName, R::Value: Mul<C::Value>, Prod<R::Value, C::Value>: ArrayLength<N> {
+    #[inline]
+    fn deref_mut(&mut self) -> &mut [N] {
+        &mut self.data[..]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::mem::MaybeUninit;
+
+    use core::dimension::{U1, U3};
+
+    use super::MatrixArray;
+
+    #[test]
+    fn assume_init_round_trips_through_new_uninitialized() {
+        let mut buf: MatrixArray<MaybeUninit<f64>, U3, U1> = MatrixArray::new_uninitialized();
+
+        for (i, e) in buf.as_mut_slice().iter_mut().enumerate() {
+            *e = MaybeUninit::new(i as f64);
+        }
+
+        let buf = unsafe { buf.assume_init() };
+        assert_eq!(buf.as_slice(), &[0.0, 1.0, 2.0]);
+    }
+}
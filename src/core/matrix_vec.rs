@@ -0,0 +1,157 @@
+use std::mem;
+use std::mem::MaybeUninit;
+use std::ops::{Deref, DerefMut};
+use std::ptr;
+
+use core::dimension::Dim;
+
+/// A Vec-based matrix data storage. It may be dynamically-sized.
+#[repr(C)]
+#[derive(Eq, Debug, Clone, PartialEq)]
+pub struct MatrixVec<N, R: Dim, C: Dim> {
+    data:  Vec<N>,
+    nrows: R,
+    ncols: C,
+}
+
+impl<N, R: Dim, C: Dim> MatrixVec<N, R, C> {
+    /// Creates a new dynamic matrix data storage from the given vector and shape.
+    #[inline]
+    pub fn new(nrows: R, ncols: C, data: Vec<N>) -> MatrixVec<N, R, C> {
+        assert!(nrows.value() * ncols.value() == data.len(),
+                "Data storage buffer dimension mismatch.");
+        MatrixVec { data: data, nrows: nrows, ncols: ncols }
+    }
+
+    /// The underlying data storage.
+    #[inline]
+    pub fn data(&self) -> &Vec<N> {
+        &self.data
+    }
+
+    /// The elements of this buffer as a contiguous slice.
+    #[inline]
+    pub fn as_slice(&self) -> &[N] {
+        &self.data[..]
+    }
+
+    /// The elements of this buffer as a mutable contiguous slice.
+    #[inline]
+    pub fn as_mut_slice(&mut self) -> &mut [N] {
+        &mut self.data[..]
+    }
+
+    /// The underlying mutable data storage.
+    ///
+    /// This is unsafe because this may cause UB if the vector is modified by the user and
+    /// shrunk below `nrows * ncols` elements, or resized in a way that leaves it uninitialized.
+    #[inline]
+    pub unsafe fn data_mut(&mut self) -> &mut Vec<N> {
+        &mut self.data
+    }
+
+    /// Consumes `self` and returns its inner buffer resized to `new_len` elements in place, as
+    /// a `Vec<MaybeUninit<N>>`.
+    ///
+    /// If `new_len` is smaller than the current length the extra tail elements are dropped and
+    /// truncated away. If it is larger, the new trailing slots are left as `MaybeUninit`: unlike
+    /// calling `Vec::set_len` directly on a `Vec<N>`, those slots are never briefly treated as
+    /// live `N` values, so the caller must write every new slot and call `assume_init` before
+    /// the result is read or dropped as a `Vec<N>`.
+    #[inline]
+    pub unsafe fn resize(self, new_len: usize) -> Vec<MaybeUninit<N>> {
+        let mut me = mem::ManuallyDrop::new(self);
+        let len = me.data.len();
+        let cap = me.data.capacity();
+        let ptr = me.data.as_mut_ptr() as *mut MaybeUninit<N>;
+        let mut data = Vec::from_raw_parts(ptr, len, cap);
+
+        if new_len > len {
+            data.reserve_exact(new_len - len);
+        }
+        else {
+            for e in &mut data[new_len..len] {
+                ptr::drop_in_place(e.as_mut_ptr());
+            }
+        }
+
+        data.set_len(new_len);
+        data
+    }
+}
+
+impl<N, R: Dim, C: Dim> MatrixVec<MaybeUninit<N>, R, C> {
+    /// Asserts that every element of this vector has been initialized, and converts it into a
+    /// fully initialized `MatrixVec<N, R, C>`.
+    ///
+    /// # Safety
+    /// The caller must ensure that every element has actually been written to.
+    #[inline]
+    pub unsafe fn assume_init(self) -> MatrixVec<N, R, C> {
+        let mut me = mem::ManuallyDrop::new(self);
+        let data = Vec::from_raw_parts(me.data.as_mut_ptr() as *mut N, me.data.len(), me.data.capacity());
+
+        MatrixVec::new(me.nrows, me.ncols, data)
+    }
+}
+
+impl<N, R: Dim, C: Dim> Deref for MatrixVec<N, R, C> {
+    type Target = [N];
+
+    #[inline]
+    fn deref(&self) -> &[N] {
+        &self.data[..]
+    }
+}
+
+impl<N, R: Dim, C: Dim> DerefMut for MatrixVec<N, R, C> {
+    #[inline]
+    fn deref_mut(&mut self) -> &mut [N] {
+        &mut self.data[..]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::Cell;
+    use std::mem::MaybeUninit;
+    use std::rc::Rc;
+
+    use core::dimension::Dynamic;
+
+    use super::MatrixVec;
+
+    #[test]
+    fn resize_grow_preserves_existing_elements() {
+        let buf = MatrixVec::new(Dynamic::new(3), Dynamic::new(1), vec![1i32, 2, 3]);
+
+        let mut grown = unsafe { buf.resize(5) };
+        grown[3] = MaybeUninit::new(4);
+        grown[4] = MaybeUninit::new(5);
+
+        let grown = unsafe { MatrixVec::new(Dynamic::new(5), Dynamic::new(1), grown).assume_init() };
+        assert_eq!(grown.as_slice(), &[1, 2, 3, 4, 5]);
+    }
+
+    struct DropCounter(Rc<Cell<usize>>);
+
+    impl Drop for DropCounter {
+        fn drop(&mut self) {
+            self.0.set(self.0.get() + 1);
+        }
+    }
+
+    #[test]
+    fn resize_shrink_preserves_prefix_and_drops_tail() {
+        let count = Rc::new(Cell::new(0));
+        let data  = vec![DropCounter(count.clone()), DropCounter(count.clone()), DropCounter(count.clone())];
+        let buf   = MatrixVec::new(Dynamic::new(3), Dynamic::new(1), data);
+
+        let shrunk = unsafe { buf.resize(1) };
+        assert_eq!(count.get(), 2, "the two truncated-away elements must be dropped immediately");
+
+        let shrunk = unsafe { MatrixVec::new(Dynamic::new(1), Dynamic::new(1), shrunk).assume_init() };
+        drop(shrunk);
+        assert_eq!(count.get(), 3);
+    }
+}